@@ -1,14 +1,152 @@
-use tokio::io::{stdin, stdout};
+use std::{net::SocketAddr, sync::Arc};
 
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, StatusCode, header::AUTHORIZATION},
+    middleware::{self, Next},
+    response::Response,
+};
 use hyprland_mcp::server::HyprlandMcpServer;
 use rmcp::ServiceExt;
+use rmcp::transport::sse_server::{SseServer, SseServerConfig};
+use tokio::io::{stdin, stdout};
+use tokio_util::sync::CancellationToken;
+
+enum Transport {
+    Stdio,
+    Sse { bind: SocketAddr, token: String },
+}
+
+fn parse_args() -> anyhow::Result<Transport> {
+    let mut transport = "stdio".to_string();
+    let mut bind = "127.0.0.1:8000".to_string();
+    let mut token = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--transport" => {
+                transport = args
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("--transport requires a value"))?;
+            }
+            "--bind" => {
+                bind = args
+                    .next()
+                    .ok_or_else(|| anyhow::anyhow!("--bind requires a value"))?;
+            }
+            "--token" => {
+                token = Some(
+                    args.next()
+                        .ok_or_else(|| anyhow::anyhow!("--token requires a value"))?,
+                );
+            }
+            other => anyhow::bail!("unknown argument: {other}"),
+        }
+    }
+
+    match transport.as_str() {
+        "stdio" => Ok(Transport::Stdio),
+        "sse" => {
+            let bind: SocketAddr = bind.parse()?;
+            let token = token.or_else(|| std::env::var("HYPRLAND_MCP_TOKEN").ok()).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "--transport sse requires a shared secret: pass --token <secret> or set \
+                     HYPRLAND_MCP_TOKEN. This transport exposes hyprctl/dispatch/batch \
+                     (including arbitrary `dispatch exec`) to anything that can reach --bind."
+                )
+            })?;
+
+            if !bind.ip().is_loopback() {
+                eprintln!(
+                    "WARNING: --bind {bind} is not loopback. Anyone who can reach this \
+                     address can run arbitrary Hyprland dispatches, including `dispatch exec`, \
+                     through this server. Only widen --bind behind a firewall/VPN you trust, \
+                     and never expose it directly to the open internet."
+                );
+            }
+
+            Ok(Transport::Sse { bind, token })
+        }
+        other => anyhow::bail!("unknown --transport: {other} (expected \"stdio\" or \"sse\")"),
+    }
+}
+
+/// Compares two byte strings in constant time with respect to their contents
+/// (the running time depends only on the lengths, not on where a mismatch
+/// occurs), to avoid leaking the shared secret through response-time side
+/// channels.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+async fn require_bearer_token(
+    State(token): State<Arc<String>>,
+    headers: HeaderMap,
+    request: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let provided = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    let authorized = provided.is_some_and(|provided| constant_time_eq(provided.as_bytes(), token.as_bytes()));
+
+    if authorized {
+        Ok(next.run(request).await)
+    } else {
+        Err(StatusCode::UNAUTHORIZED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn constant_time_eq_matches_equal_and_unequal_inputs() {
+        assert!(constant_time_eq(b"secret-token", b"secret-token"));
+        assert!(!constant_time_eq(b"secret-token", b"secret-tokeN"));
+        assert!(!constant_time_eq(b"short", b"much-longer-secret"));
+    }
+}
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
-    let transport = (stdin(), stdout());
+    let transport = parse_args()?;
     let server = HyprlandMcpServer::new()?;
-    let running = server.serve(transport).await?;
-    running.waiting().await?;
+
+    match transport {
+        Transport::Stdio => {
+            let running = server.serve((stdin(), stdout())).await?;
+            running.waiting().await?;
+        }
+        Transport::Sse { bind, token } => {
+            let ct = CancellationToken::new();
+            let config = SseServerConfig {
+                bind,
+                sse_path: "/sse".to_string(),
+                post_path: "/message".to_string(),
+                ct: ct.clone(),
+                sse_keep_alive: None,
+            };
+
+            let (sse_server, router) = SseServer::new(config);
+            let router = router.layer(middleware::from_fn_with_state(Arc::new(token), require_bearer_token));
+
+            let service_ct = sse_server.with_service(move || server.clone());
+            let listener = tokio::net::TcpListener::bind(bind).await?;
+
+            axum::serve(listener, router)
+                .with_graceful_shutdown(async move { service_ct.cancelled().await })
+                .await?;
+        }
+    }
 
     Ok(())
 }
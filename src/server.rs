@@ -1,85 +1,1219 @@
 use std::{
+    collections::HashMap,
     io::{Read, Write},
     net::Shutdown,
     os::unix::net::UnixStream,
+    path::PathBuf,
+    sync::Arc,
+    time::Duration,
 };
 
 use rmcp::{
-    ServerHandler,
+    Peer, RoleServer, ServerHandler,
     handler::server::{tool::ToolRouter, wrapper::Parameters},
-    model::ServerInfo,
+    model::{
+        Implementation, LoggingLevel, LoggingMessageNotificationParam, ServerCapabilities,
+        ServerInfo,
+    },
     schemars::JsonSchema,
     tool, tool_handler, tool_router,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use tokio::{
+    io::{AsyncBufReadExt, BufReader},
+    net::UnixStream as AsyncUnixStream,
+    sync::{Mutex, mpsc},
+    task::JoinHandle,
+};
+
+/// A single event parsed off `.socket2.sock`, e.g. `workspace>>2` or
+/// `openwindow>>ADDR,workspace,class,title`.
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+pub struct HyprEvent {
+    pub name: String,
+    pub fields: Vec<String>,
+}
+
+impl HyprEvent {
+    fn parse(line: &str) -> Option<Self> {
+        let (name, data) = line.split_once(">>")?;
+        Some(Self {
+            name: name.to_string(),
+            fields: data.split(',').map(str::to_string).collect(),
+        })
+    }
+}
+
+struct EventSubscription {
+    task: JoinHandle<()>,
+    shutdown: mpsc::Sender<()>,
+}
+
+/// The Hyprland version detected at startup, parsed from `version`'s raw output.
+/// `semver` is `None` when the socket was unreachable or the output was unparsable,
+/// in which case `new()` still succeeds and version-gated tools are treated as
+/// unsupported: they return an error reporting the version as unknown.
+#[derive(Debug, Clone, Default)]
+pub struct HyprlandVersion {
+    raw: String,
+    semver: Option<(u32, u32, u32)>,
+}
+
+impl HyprlandVersion {
+    fn detect(sock: &str) -> Self {
+        let raw = UnixStream::connect(sock)
+            .and_then(|mut stream| {
+                stream.write_all(b"version")?;
+                stream.shutdown(Shutdown::Write)?;
+
+                let mut out = String::new();
+                stream.read_to_string(&mut out)?;
+                Ok(out)
+            })
+            .unwrap_or_default();
+
+        let semver = Self::parse_semver(&raw);
+        Self { raw, semver }
+    }
+
+    /// Scans every whitespace-separated, digit-leading token for one shaped like
+    /// `major.minor.patch`, skipping any that don't parse (e.g. a commit hash or a
+    /// build date that happens to start with a digit) rather than giving up at the
+    /// first candidate.
+    fn parse_semver(raw: &str) -> Option<(u32, u32, u32)> {
+        raw.split_whitespace()
+            .filter(|tok| tok.chars().next().is_some_and(|c| c.is_ascii_digit()))
+            .find_map(Self::parse_semver_token)
+    }
+
+    fn parse_semver_token(tok: &str) -> Option<(u32, u32, u32)> {
+        let tok = tok.trim_matches(|c: char| !c.is_ascii_digit() && c != '.');
+        let mut parts = tok.splitn(3, '.');
+
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next()?.parse().ok()?;
+        let patch = parts
+            .next()?
+            .split(|c: char| !c.is_ascii_digit())
+            .next()?
+            .parse()
+            .ok()?;
+
+        Some((major, minor, patch))
+    }
+
+    fn at_least(&self, major: u32, minor: u32, patch: u32) -> bool {
+        self.semver.is_some_and(|v| v >= (major, minor, patch))
+    }
+
+    fn display(&self) -> &str {
+        if self.semver.is_some() {
+            self.raw.lines().next().unwrap_or("unknown").trim()
+        } else {
+            "unknown"
+        }
+    }
+}
+
+/// Output format for query tools: raw hyprctl text, or validated, structured JSON.
+#[derive(Debug, Clone, Copy, Default, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    Text,
+    #[default]
+    Json,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct WorkspaceRef {
+    pub id: i32,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Client {
+    pub address: String,
+    pub mapped: bool,
+    pub hidden: bool,
+    pub at: (i32, i32),
+    pub size: (i32, i32),
+    pub workspace: WorkspaceRef,
+    pub floating: bool,
+    pub monitor: i32,
+    pub class: String,
+    pub title: String,
+    pub pid: i32,
+    pub xwayland: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Monitor {
+    pub id: i32,
+    pub name: String,
+    pub width: i32,
+    pub height: i32,
+    pub refresh_rate: f32,
+    pub x: i32,
+    pub y: i32,
+    pub active_workspace: WorkspaceRef,
+    pub focused: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Workspace {
+    pub id: i32,
+    pub name: String,
+    pub monitor: String,
+    #[serde(rename = "monitorID")]
+    pub monitor_id: i32,
+    pub windows: i32,
+    pub hasfullscreen: bool,
+    pub lastwindow: String,
+    pub lastwindowtitle: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Bind {
+    pub locked: bool,
+    pub mouse: bool,
+    pub release: bool,
+    pub repeat: bool,
+    pub modmask: i32,
+    pub submap: String,
+    pub key: String,
+    #[serde(rename = "keycode")]
+    pub key_code: i32,
+    pub dispatcher: String,
+    pub arg: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct MouseDevice {
+    pub address: String,
+    pub name: String,
+    #[serde(rename = "defaultSpeed")]
+    pub default_speed: f32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct KeyboardDevice {
+    pub address: String,
+    pub name: String,
+    pub rules: String,
+    pub model: String,
+    pub layout: String,
+    pub variant: String,
+    pub options: String,
+    pub active_keymap: String,
+    pub main: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TabletDevice {
+    pub address: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TouchDevice {
+    pub address: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct SwitchDevice {
+    pub address: String,
+    pub name: String,
+}
+
+/// The nested shape of `devices -j`: a grouping of attached input devices by kind,
+/// not a flat list.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Devices {
+    pub mice: Vec<MouseDevice>,
+    pub keyboards: Vec<KeyboardDevice>,
+    pub tablets: Vec<TabletDevice>,
+    pub touch: Vec<TouchDevice>,
+    pub switches: Vec<SwitchDevice>,
+}
 
-#[derive(Clone)]
 pub struct HyprlandMcpServer {
     sock: String,
+    sock2: String,
     tool_router: ToolRouter<Self>,
+    subscription: Arc<Mutex<Option<EventSubscription>>>,
+    profiles: HashMap<String, Profile>,
+    version: HyprlandVersion,
+}
+
+/// `sse_server.with_service(move || server.clone())` calls this once per incoming
+/// connection to produce that connection's own server instance. A derived `Clone`
+/// would just bump the `Arc`'s refcount, leaving every connection pointing at the
+/// same `subscription` slot and the same captured `Peer` — client B's `subscribe`
+/// would see client A's subscription and never get its own events. Each clone
+/// instead starts with fresh, empty subscription state of its own.
+impl Clone for HyprlandMcpServer {
+    fn clone(&self) -> Self {
+        Self {
+            sock: self.sock.clone(),
+            sock2: self.sock2.clone(),
+            tool_router: self.tool_router.clone(),
+            subscription: Arc::new(Mutex::new(None)),
+            profiles: self.profiles.clone(),
+            version: self.version.clone(),
+        }
+    }
+}
+
+/// Ties a subscription's event-loop task to the connection it belongs to: once the
+/// last `HyprlandMcpServer` instance sharing this `subscription` slot is dropped
+/// (the connection closed without calling `unsubscribe`), abort the task instead of
+/// leaving it reconnecting and silently swallowing failed notifies forever.
+impl Drop for HyprlandMcpServer {
+    fn drop(&mut self) {
+        if Arc::strong_count(&self.subscription) == 1 {
+            if let Ok(mut guard) = self.subscription.try_lock() {
+                if let Some(sub) = guard.take() {
+                    sub.task.abort();
+                }
+            }
+        }
+    }
+}
+
+/// A named, ordered list of hyprctl subcommands loaded from config.yml.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Profile {
+    pub commands: Vec<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Config {
+    #[serde(default)]
+    profiles: HashMap<String, Profile>,
 }
 
 #[derive(Deserialize, JsonSchema)]
-pub struct DispatchRequest {
+pub struct HyprctlRequest {
+    /// Raw hyprctl socket command passed through verbatim.
+    ///
+    /// Examples:
+    /// - "dispatch exec kitty"
+    /// - "activewindow"
     command: String,
 }
 
 #[derive(Deserialize, JsonSchema)]
 pub struct WorkspaceRequest {
+    /// Target workspace number (1-based).
     n: usize,
 }
 
+#[derive(Deserialize, JsonSchema)]
+pub struct MonitorsRequest {
+    /// Include inactive monitors.
+    all: Option<bool>,
+    /// "text" for raw hyprctl output, "json" (default) for structured data.
+    format: Option<OutputFormat>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct FormatRequest {
+    /// "text" for raw hyprctl output, "json" (default) for structured data.
+    format: Option<OutputFormat>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct DecorationsRequest {
+    /// Window regex.
+    window_regex: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct DismissNotifyRequest {
+    /// Optional number of notifications to dismiss.
+    amount: Option<usize>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct DispatchCommandRequest {
+    /// Dispatcher and arguments, e.g. "workspace 1" or "exec kitty".
+    args: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct BatchRequest {
+    /// Subcommands to run atomically over one connection, e.g.
+    /// ["keyword general:gaps_out 5", "dispatch workspace 2", "dispatch exec kitty"].
+    /// None may contain a literal ';' — Hyprland's [[BATCH]] parser has no
+    /// escaping for it and would silently split the command in two.
+    commands: Vec<String>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ApplyProfileRequest {
+    /// Name of the profile to run, as defined in config.yml.
+    name: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct OptionNameRequest {
+    /// Config option name.
+    option: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct KeywordRequest {
+    /// Keyword name.
+    name: String,
+    /// Keyword value.
+    value: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct NotifyRequest {
+    /// Icon ID used by Hyprland notifications.
+    icon: i32,
+    /// Timeout in milliseconds.
+    timeout_ms: u32,
+    /// Color string, e.g. "rgb(7fd16a)".
+    color: String,
+    /// Notification message body.
+    message: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct RawArgsRequest {
+    /// Raw arguments passed to the subcommand.
+    args: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct ReloadRequest {
+    /// If true, run "reload config-only".
+    config_only: Option<bool>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct RollingLogRequest {
+    /// Follow rolling log stream.
+    follow: Option<bool>,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct SetCursorRequest {
+    theme: String,
+    size: u32,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct SetErrorRequest {
+    color: String,
+    message: String,
+}
+
+#[derive(Deserialize, JsonSchema)]
+pub struct SwitchXkbLayoutRequest {
+    keyboard: String,
+    command: String,
+}
+
 impl HyprlandMcpServer {
     pub fn new() -> Result<Self, std::env::VarError> {
         let xdg = std::env::var("XDG_RUNTIME_DIR")?;
         let instance = std::env::var("HYPRLAND_INSTANCE_SIGNATURE")?;
         let sock = format!("{xdg}/hypr/{instance}/.socket.sock");
+        let sock2 = format!("{xdg}/hypr/{instance}/.socket2.sock");
+
+        let version = HyprlandVersion::detect(&sock);
 
         Ok(Self {
             sock,
+            sock2,
             tool_router: Self::tool_router(),
+            subscription: Arc::new(Mutex::new(None)),
+            profiles: Self::load_profiles(),
+            version,
         })
     }
 
-    fn open(&self) -> Result<UnixStream, std::io::Error> {
-        Ok(UnixStream::connect(&self.sock)?)
+    fn config_path() -> Option<PathBuf> {
+        let config_home = std::env::var("XDG_CONFIG_HOME")
+            .or_else(|_| std::env::var("HOME").map(|home| format!("{home}/.config")))
+            .ok()?;
+
+        Some(PathBuf::from(config_home).join("hyprland-mcp").join("config.yml"))
+    }
+
+    /// Loads `profiles` from config.yml, if present. A missing or unparsable
+    /// config file just means no profiles are available.
+    fn load_profiles() -> HashMap<String, Profile> {
+        let Some(path) = Self::config_path() else {
+            return HashMap::new();
+        };
+
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return HashMap::new();
+        };
+
+        serde_yaml::from_str::<Config>(&contents)
+            .map(|config| config.profiles)
+            .unwrap_or_default()
     }
 
     pub fn cmd(&self, cmd: &str) -> Result<String, std::io::Error> {
-        let mut f = self.open()?;
-        f.write_all(cmd.as_bytes())?;
-        f.shutdown(Shutdown::Write)?;
+        let mut sock = UnixStream::connect(&self.sock)?;
+        sock.write_all(cmd.as_bytes())?;
+        sock.shutdown(Shutdown::Write)?;
 
         let mut out = String::new();
-        f.read_to_string(&mut out)?;
+        sock.read_to_string(&mut out)?;
 
         Ok(out)
     }
+
+    /// Like `cmd`, but requests the `j/`-prefixed JSON form of `subcommand`.
+    pub fn cmd_json(&self, subcommand: &str) -> Result<String, std::io::Error> {
+        self.cmd(&format!("j/{subcommand}"))
+    }
+
+    /// Runs `subcommand` in the requested format. For JSON, deserializes into `T` and
+    /// re-serializes the validated value; falls back to raw text if parsing fails so
+    /// unknown or changed fields don't break the tool.
+    fn query<T: serde::de::DeserializeOwned + Serialize>(
+        &self,
+        subcommand: &str,
+        format: OutputFormat,
+    ) -> Result<String, String> {
+        match format {
+            OutputFormat::Text => self.cmd(subcommand).map_err(|e| e.to_string()),
+            OutputFormat::Json => {
+                let raw = self.cmd_json(subcommand).map_err(|e| e.to_string())?;
+                match serde_json::from_str::<T>(&raw) {
+                    Ok(value) => serde_json::to_string(&value).map_err(|e| e.to_string()),
+                    Err(_) => Ok(raw),
+                }
+            }
+        }
+    }
+
+    fn quote(arg: &str) -> String {
+        format!("\"{}\"", arg.replace('"', "\\\""))
+    }
 }
 
 #[tool_router]
 impl HyprlandMcpServer {
-    #[tool(description = "Dispatch hyprland command")]
-    async fn dispatch(
+    // lib.rs never declared `pub mod server;`, so `main.rs`'s
+    // `hyprland_mcp::server::HyprlandMcpServer` didn't resolve and the crate never
+    // compiled; lib.rs's duplicate definitions (including this raw-passthrough tool,
+    // previously also named `dispatch`) were dead code. Reconciled here as `hyprctl`,
+    // leaving `dispatch` below as the dispatcher-only tool.
+    #[tool(description = "Run a raw hyprctl-style command via Hyprland socket")]
+    async fn hyprctl(
         &self,
-        Parameters(DispatchRequest { command }): Parameters<DispatchRequest>,
+        Parameters(HyprctlRequest { command }): Parameters<HyprctlRequest>,
     ) -> Result<String, String> {
         self.cmd(&command).map_err(|e| e.to_string())
     }
 
-    #[tool(description = "Go to workspace n")]
+    #[tool(description = "Switch to a numbered workspace")]
     async fn workspace(
         &self,
         Parameters(WorkspaceRequest { n }): Parameters<WorkspaceRequest>,
     ) -> Result<String, String> {
-        let command = format!("dispatch workspace {n}");
-        self.cmd(&command).map_err(|e| e.to_string())
+        if n == 0 {
+            return Err("workspace must be >= 1".to_string());
+        }
+
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: format!("dispatch workspace {n}"),
+        }))
+        .await
+    }
+
+    #[tool(description = "Get active window info")]
+    async fn activewindow(
+        &self,
+        Parameters(FormatRequest { format }): Parameters<FormatRequest>,
+    ) -> Result<String, String> {
+        self.query::<Client>("activewindow", format.unwrap_or_default())
+    }
+
+    #[tool(description = "Get active workspace info")]
+    async fn activeworkspace(&self) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: "activeworkspace".to_string(),
+        }))
+        .await
+    }
+
+    #[tool(description = "Get animation and bezier info")]
+    async fn animations(&self) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: "animations".to_string(),
+        }))
+        .await
+    }
+
+    #[tool(description = "List registered binds")]
+    async fn binds(
+        &self,
+        Parameters(FormatRequest { format }): Parameters<FormatRequest>,
+    ) -> Result<String, String> {
+        self.query::<Vec<Bind>>("binds", format.unwrap_or_default())
+    }
+
+    #[tool(description = "List clients")]
+    async fn clients(
+        &self,
+        Parameters(FormatRequest { format }): Parameters<FormatRequest>,
+    ) -> Result<String, String> {
+        self.query::<Vec<Client>>("clients", format.unwrap_or_default())
+    }
+
+    #[tool(description = "List input devices (mice, keyboards, tablets, touch, switches)")]
+    async fn devices(
+        &self,
+        Parameters(FormatRequest { format }): Parameters<FormatRequest>,
+    ) -> Result<String, String> {
+        self.query::<Devices>("devices", format.unwrap_or_default())
+    }
+
+    #[tool(description = "List config errors")]
+    async fn configerrors(&self) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: "configerrors".to_string(),
+        }))
+        .await
+    }
+
+    #[tool(description = "Get cursor position")]
+    async fn cursorpos(&self) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: "cursorpos".to_string(),
+        }))
+        .await
+    }
+
+    #[tool(description = "List decorations for a window regex")]
+    async fn decorations(
+        &self,
+        Parameters(DecorationsRequest { window_regex }): Parameters<DecorationsRequest>,
+    ) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: format!("decorations {window_regex}"),
+        }))
+        .await
+    }
+
+    #[tool(description = "Dismiss all or a specific amount of notifications")]
+    async fn dismissnotify(
+        &self,
+        Parameters(DismissNotifyRequest { amount }): Parameters<DismissNotifyRequest>,
+    ) -> Result<String, String> {
+        let command = match amount {
+            Some(n) => format!("dismissnotify {n}"),
+            None => "dismissnotify".to_string(),
+        };
+
+        self.hyprctl(Parameters(HyprctlRequest { command })).await
+    }
+
+    #[tool(description = "Issue a Hyprland dispatch command")]
+    async fn dispatch(
+        &self,
+        Parameters(DispatchCommandRequest { args }): Parameters<DispatchCommandRequest>,
+    ) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: format!("dispatch {args}"),
+        }))
+        .await
+    }
+
+    #[tool(
+        description = "Run multiple hyprctl subcommands atomically over one connection via [[BATCH]]"
+    )]
+    async fn batch(
+        &self,
+        Parameters(BatchRequest { commands }): Parameters<BatchRequest>,
+    ) -> Result<String, String> {
+        // `Self::quote` is not used here: it quotes a single arg value (e.g. a notify
+        // message) inside a command string being built. `batch` takes already-built
+        // subcommand strings, such as ones from `notify`/`seterror` callers that quoted
+        // their own args; there's no arg-level value for `batch` itself to quote.
+        if let Some(bad) = commands.iter().find(|c| c.contains(';')) {
+            return Err(format!(
+                "batch subcommand contains a literal ';', which Hyprland's [[BATCH]] \
+                 parser treats as a command separator with no escaping: {bad:?}"
+            ));
+        }
+
+        let command = format!("[[BATCH]]{}", commands.join(" ; "));
+        self.hyprctl(Parameters(HyprctlRequest { command })).await
+    }
+
+    #[tool(description = "List named layout/profile presets loaded from config.yml")]
+    async fn list_profiles(&self) -> Result<String, String> {
+        let mut names: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+        names.sort_unstable();
+
+        serde_json::to_string(&names).map_err(|e| e.to_string())
+    }
+
+    #[tool(description = "Run all commands in a named profile preset atomically")]
+    async fn apply_profile(
+        &self,
+        Parameters(ApplyProfileRequest { name }): Parameters<ApplyProfileRequest>,
+    ) -> Result<String, String> {
+        let profile = self
+            .profiles
+            .get(&name)
+            .ok_or_else(|| format!("no such profile: {name}"))?;
+
+        self.batch(Parameters(BatchRequest {
+            commands: profile.commands.clone(),
+        }))
+        .await
+    }
+
+    #[tool(description = "Get config option value")]
+    async fn getoption(
+        &self,
+        Parameters(OptionNameRequest { option }): Parameters<OptionNameRequest>,
+    ) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: format!("getoption {option}"),
+        }))
+        .await
+    }
+
+    #[tool(description = "List global shortcuts")]
+    async fn globalshortcuts(&self) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: "globalshortcuts".to_string(),
+        }))
+        .await
+    }
+
+    #[tool(description = "List Hyprland instances")]
+    async fn instances(&self) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: "instances".to_string(),
+        }))
+        .await
+    }
+
+    #[tool(description = "Dynamically set a Hyprland keyword")]
+    async fn keyword(
+        &self,
+        Parameters(KeywordRequest { name, value }): Parameters<KeywordRequest>,
+    ) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: format!("keyword {name} {value}"),
+        }))
+        .await
+    }
+
+    #[tool(description = "Enter Hyprland kill mode")]
+    async fn kill(&self) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: "kill".to_string(),
+        }))
+        .await
+    }
+
+    #[tool(description = "List layer surfaces")]
+    async fn layers(&self) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: "layers".to_string(),
+        }))
+        .await
+    }
+
+    #[tool(description = "List available layouts")]
+    async fn layouts(&self) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: "layouts".to_string(),
+        }))
+        .await
+    }
+
+    #[tool(description = "List monitors")]
+    async fn monitors(
+        &self,
+        Parameters(MonitorsRequest { all, format }): Parameters<MonitorsRequest>,
+    ) -> Result<String, String> {
+        let command = if all.unwrap_or(false) {
+            "monitors all"
+        } else {
+            "monitors"
+        };
+
+        self.query::<Vec<Monitor>>(command, format.unwrap_or_default())
+    }
+
+    #[tool(description = "Send a Hyprland notification")]
+    async fn notify(
+        &self,
+        Parameters(NotifyRequest {
+            icon,
+            timeout_ms,
+            color,
+            message,
+        }): Parameters<NotifyRequest>,
+    ) -> Result<String, String> {
+        let command = format!(
+            "notify {icon} {timeout_ms} {color} {}",
+            Self::quote(&message)
+        );
+        self.hyprctl(Parameters(HyprctlRequest { command })).await
+    }
+
+    #[tool(description = "Issue an output subcommand")]
+    async fn output(
+        &self,
+        Parameters(RawArgsRequest { args }): Parameters<RawArgsRequest>,
+    ) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: format!("output {args}"),
+        }))
+        .await
+    }
+
+    #[tool(description = "Issue a plugin subcommand")]
+    async fn plugin(
+        &self,
+        Parameters(RawArgsRequest { args }): Parameters<RawArgsRequest>,
+    ) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: format!("plugin {args}"),
+        }))
+        .await
+    }
+
+    #[tool(description = "Reload Hyprland config")]
+    async fn reload(
+        &self,
+        Parameters(ReloadRequest { config_only }): Parameters<ReloadRequest>,
+    ) -> Result<String, String> {
+        let command = if config_only.unwrap_or(false) {
+            "reload config-only".to_string()
+        } else {
+            "reload".to_string()
+        };
+
+        self.hyprctl(Parameters(HyprctlRequest { command })).await
+    }
+
+    #[tool(description = "Get rolling log")]
+    async fn rollinglog(
+        &self,
+        Parameters(RollingLogRequest { follow }): Parameters<RollingLogRequest>,
+    ) -> Result<String, String> {
+        let command = if follow.unwrap_or(false) {
+            "rollinglog -f".to_string()
+        } else {
+            "rollinglog".to_string()
+        };
+
+        self.hyprctl(Parameters(HyprctlRequest { command })).await
+    }
+
+    #[tool(description = "Set cursor theme and size")]
+    async fn setcursor(
+        &self,
+        Parameters(SetCursorRequest { theme, size }): Parameters<SetCursorRequest>,
+    ) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: format!("setcursor {theme} {size}"),
+        }))
+        .await
+    }
+
+    #[tool(description = "Set Hyprland error string")]
+    async fn seterror(
+        &self,
+        Parameters(SetErrorRequest { color, message }): Parameters<SetErrorRequest>,
+    ) -> Result<String, String> {
+        let command = format!("seterror {color} {}", Self::quote(&message));
+        self.hyprctl(Parameters(HyprctlRequest { command })).await
+    }
+
+    #[tool(description = "Set window property (lock flags require Hyprland >= 0.38.0)")]
+    async fn setprop(
+        &self,
+        Parameters(RawArgsRequest { args }): Parameters<RawArgsRequest>,
+    ) -> Result<String, String> {
+        if args.split_whitespace().last() == Some("lock") && !self.version.at_least(0, 38, 0) {
+            return Err(format!(
+                "setprop lock flags require Hyprland >= 0.38.0 (detected: {})",
+                self.version.display()
+            ));
+        }
+
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: format!("setprop {args}"),
+        }))
+        .await
+    }
+
+    #[tool(description = "Get window property")]
+    async fn getprop(
+        &self,
+        Parameters(RawArgsRequest { args }): Parameters<RawArgsRequest>,
+    ) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: format!("getprop {args}"),
+        }))
+        .await
+    }
+
+    #[tool(description = "Get splash text")]
+    async fn splash(&self) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: "splash".to_string(),
+        }))
+        .await
+    }
+
+    #[tool(description = "Switch keyboard layout")]
+    async fn switchxkblayout(
+        &self,
+        Parameters(SwitchXkbLayoutRequest { keyboard, command }): Parameters<SwitchXkbLayoutRequest>,
+    ) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: format!("switchxkblayout {keyboard} {command}"),
+        }))
+        .await
+    }
+
+    #[tool(description = "Get system info")]
+    async fn systeminfo(&self) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: "systeminfo".to_string(),
+        }))
+        .await
+    }
+
+    #[tool(description = "Get Hyprland version")]
+    async fn version(&self) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: "version".to_string(),
+        }))
+        .await
+    }
+
+    #[tool(description = "List workspace rules")]
+    async fn workspacerules(&self) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: "workspacerules".to_string(),
+        }))
+        .await
+    }
+
+    #[tool(description = "List workspaces")]
+    async fn workspaces(
+        &self,
+        Parameters(FormatRequest { format }): Parameters<FormatRequest>,
+    ) -> Result<String, String> {
+        self.query::<Vec<Workspace>>("workspaces", format.unwrap_or_default())
+    }
+
+    #[tool(description = "Issue a hyprpaper request")]
+    async fn hyprpaper(
+        &self,
+        Parameters(RawArgsRequest { args }): Parameters<RawArgsRequest>,
+    ) -> Result<String, String> {
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: format!("hyprpaper {args}"),
+        }))
+        .await
+    }
+
+    #[tool(description = "Issue a hyprsunset request (requires Hyprland >= 0.40.0)")]
+    async fn hyprsunset(
+        &self,
+        Parameters(RawArgsRequest { args }): Parameters<RawArgsRequest>,
+    ) -> Result<String, String> {
+        if !self.version.at_least(0, 40, 0) {
+            return Err(format!(
+                "hyprsunset requires Hyprland >= 0.40.0 (detected: {})",
+                self.version.display()
+            ));
+        }
+
+        self.hyprctl(Parameters(HyprctlRequest {
+            command: format!("hyprsunset {args}"),
+        }))
+        .await
+    }
+
+    #[tool(
+        description = "Start streaming Hyprland events (workspace/window/monitor changes) from .socket2.sock as logging notifications"
+    )]
+    async fn subscribe(&self, peer: Peer<RoleServer>) -> Result<String, String> {
+        let mut guard = self.subscription.lock().await;
+        if guard.is_some() {
+            return Ok("already subscribed".to_string());
+        }
+
+        let (shutdown, shutdown_rx) = mpsc::channel::<()>(1);
+        let task = tokio::spawn(Self::run_event_loop(self.sock2.clone(), peer, shutdown_rx));
+        *guard = Some(EventSubscription { task, shutdown });
+
+        Ok("subscribed".to_string())
+    }
+
+    #[tool(description = "Stop streaming events started by `subscribe`")]
+    async fn unsubscribe(&self) -> Result<String, String> {
+        let mut guard = self.subscription.lock().await;
+        match guard.take() {
+            Some(sub) => {
+                let _ = sub.shutdown.send(()).await;
+                sub.task.abort();
+                Ok("unsubscribed".to_string())
+            }
+            None => Ok("not subscribed".to_string()),
+        }
+    }
+}
+
+impl HyprlandMcpServer {
+    /// Reads newline-delimited `EVENT>>DATA` lines from `.socket2.sock`, forwarding
+    /// each as a logging notification. Reconnects on disconnect; exits on shutdown.
+    async fn run_event_loop(
+        sock2: String,
+        peer: Peer<RoleServer>,
+        mut shutdown_rx: mpsc::Receiver<()>,
+    ) {
+        loop {
+            let stream = tokio::select! {
+                _ = shutdown_rx.recv() => return,
+                res = AsyncUnixStream::connect(&sock2) => res,
+            };
+
+            let stream = match stream {
+                Ok(stream) => stream,
+                Err(_) => {
+                    tokio::select! {
+                        _ = shutdown_rx.recv() => return,
+                        _ = tokio::time::sleep(Duration::from_secs(1)) => continue,
+                    }
+                }
+            };
+
+            let mut lines = BufReader::new(stream).lines();
+            loop {
+                let line = tokio::select! {
+                    _ = shutdown_rx.recv() => return,
+                    line = lines.next_line() => line,
+                };
+
+                match line {
+                    Ok(Some(line)) => {
+                        if let Some(event) = HyprEvent::parse(&line) {
+                            let _ = peer
+                                .notify_logging_message(LoggingMessageNotificationParam {
+                                    level: LoggingLevel::Info,
+                                    logger: Some("hyprland-events".to_string()),
+                                    data: serde_json::json!(event),
+                                })
+                                .await;
+                        }
+                    }
+                    Ok(None) | Err(_) => break,
+                }
+            }
+        }
     }
 }
 
 #[tool_handler]
 impl ServerHandler for HyprlandMcpServer {
     fn get_info(&self) -> ServerInfo {
-        ServerInfo::default()
+        ServerInfo {
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_logging()
+                .build(),
+            server_info: Implementation {
+                name: env!("CARGO_PKG_NAME").to_string(),
+                title: Some("Hyprland MCP".to_string()),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+                description: Some(
+                    "Local MCP server for controlling Hyprland through its unix socket.".to_string(),
+                ),
+                icons: None,
+                website_url: Some("https://wiki.hypr.land".to_string()),
+            },
+            instructions: Some(format!(
+                "Use dedicated tools for each hyprctl subcommand. For advanced behavior, use `hyprctl` with a raw command string. Call `subscribe` to receive workspace/window events as logging notifications, `unsubscribe` to stop. Detected Hyprland version: {}.",
+                self.version.display()
+            )),
+            ..ServerInfo::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Regression test for the `Bind` model drifting from Hyprland's real `binds -j`
+    /// field names (caught once already: `key_code` vs. the socket's `keycode`).
+    #[test]
+    fn bind_deserializes_from_sample_binds_json() {
+        let sample = r#"[
+            {
+                "locked": false,
+                "mouse": false,
+                "release": false,
+                "repeat": false,
+                "modmask": 64,
+                "submap": "",
+                "key": "Return",
+                "keycode": 0,
+                "catch_all": false,
+                "dispatcher": "exec",
+                "arg": "kitty"
+            }
+        ]"#;
+
+        let binds: Vec<Bind> = serde_json::from_str(sample).expect("Bind should match binds -j output");
+        assert_eq!(binds[0].key_code, 0);
+        assert_eq!(binds[0].dispatcher, "exec");
+    }
+
+    #[test]
+    fn parse_semver_reads_a_realistic_version_payload() {
+        let raw = "Hyprland 0.41.2 built from branch tags/v0.41.2 at commit \
+                    9f4d2a3b1c5e6f708192a3b4c5d6e7f809182736 (clean)\n\
+                    Tag: v0.41.2, commit: 9f4d2a3b1c5e6f708192a3b4c5d6e7f809182736\n\
+                    flags: (none)\n";
+
+        assert_eq!(HyprlandVersion::parse_semver(raw), Some((0, 41, 2)));
+    }
+
+    #[test]
+    fn parse_semver_skips_digit_leading_noise_before_the_real_version() {
+        // A build date and a commit hash both start with a digit but have no
+        // major.minor.patch shape; the scan must not give up on either and should
+        // keep looking until it finds the real version token.
+        let raw = "built 20240115 rev 9f4d2a3 Hyprland 0.41.2 (clean)";
+
+        assert_eq!(HyprlandVersion::parse_semver(raw), Some((0, 41, 2)));
+    }
+
+    #[test]
+    fn parse_semver_returns_none_for_unparsable_output() {
+        assert_eq!(HyprlandVersion::parse_semver(""), None);
+        assert_eq!(HyprlandVersion::parse_semver("connection refused"), None);
+    }
+
+    /// Regression test for `Client`'s field mapping against `clients -j`'s real shape.
+    #[test]
+    fn client_deserializes_from_sample_clients_json() {
+        let sample = r#"[
+            {
+                "address": "0x55d2b1a1a1a0",
+                "mapped": true,
+                "hidden": false,
+                "at": [0, 0],
+                "size": [1920, 1080],
+                "workspace": {"id": 1, "name": "1"},
+                "floating": false,
+                "monitor": 0,
+                "class": "kitty",
+                "title": "kitty",
+                "pid": 1234,
+                "xwayland": false
+            }
+        ]"#;
+
+        let clients: Vec<Client> =
+            serde_json::from_str(sample).expect("Client should match clients -j output");
+        assert_eq!(clients[0].class, "kitty");
+        assert_eq!(clients[0].workspace.id, 1);
+    }
+
+    /// Regression test for `Monitor`'s field mapping against `monitors -j`'s real shape.
+    #[test]
+    fn monitor_deserializes_from_sample_monitors_json() {
+        let sample = r#"[
+            {
+                "id": 0,
+                "name": "DP-1",
+                "width": 1920,
+                "height": 1080,
+                "refreshRate": 59.96,
+                "x": 0,
+                "y": 0,
+                "activeWorkspace": {"id": 1, "name": "1"},
+                "focused": true
+            }
+        ]"#;
+
+        let monitors: Vec<Monitor> =
+            serde_json::from_str(sample).expect("Monitor should match monitors -j output");
+        assert_eq!(monitors[0].name, "DP-1");
+        assert_eq!(monitors[0].active_workspace.id, 1);
+    }
+
+    /// Regression test for `Workspace`'s field mapping against `workspaces -j`'s real shape.
+    #[test]
+    fn workspace_deserializes_from_sample_workspaces_json() {
+        let sample = r#"[
+            {
+                "id": 1,
+                "name": "1",
+                "monitor": "DP-1",
+                "monitorID": 0,
+                "windows": 2,
+                "hasfullscreen": false,
+                "lastwindow": "0x55d2b1a1a1a0",
+                "lastwindowtitle": "kitty"
+            }
+        ]"#;
+
+        let workspaces: Vec<Workspace> =
+            serde_json::from_str(sample).expect("Workspace should match workspaces -j output");
+        assert_eq!(workspaces[0].monitor_id, 0);
+    }
+
+    /// Regression test for `Devices`'s nested shape against `devices -j`'s real shape.
+    #[test]
+    fn devices_deserializes_from_sample_devices_json() {
+        let sample = r#"{
+            "mice": [
+                {"address": "0x1", "name": "logitech-mouse", "defaultSpeed": 0.0}
+            ],
+            "keyboards": [
+                {
+                    "address": "0x2",
+                    "name": "at-translated-set-2-keyboard",
+                    "rules": "",
+                    "model": "",
+                    "layout": "",
+                    "variant": "",
+                    "options": "",
+                    "active_keymap": "English (US)",
+                    "main": true
+                }
+            ],
+            "tablets": [],
+            "touch": [],
+            "switches": [
+                {"address": "0x3", "name": "lid-switch"}
+            ]
+        }"#;
+
+        let devices: Devices =
+            serde_json::from_str(sample).expect("Devices should match devices -j output");
+        assert_eq!(devices.mice[0].name, "logitech-mouse");
+        assert_eq!(devices.keyboards[0].active_keymap, "English (US)");
+        assert_eq!(devices.switches[0].name, "lid-switch");
     }
 }